@@ -0,0 +1,133 @@
+//! Pluggable persistence for encoded-swap audit records.
+//!
+//! Every [`crate::encoding::swap_encoder::SwapEncoder::encode_swap`] call can be recorded through a
+//! [`SwapAuditStore`], letting external tooling read execution history straight from the backing
+//! store and reconcile what was encoded against what landed on-chain. The store is opt-in: the
+//! default [`NoopAuditStore`] does nothing, and recording is best-effort — a failing or unavailable
+//! backend never fails the encode path. Records are written synchronously on the encoding thread.
+
+use tycho_common::{models::Chain, Bytes};
+
+/// A single encoded-swap record, capturing everything needed to replay or reconcile a swap.
+#[derive(Clone, Debug)]
+pub struct SwapAuditEntry {
+    pub chain: Chain,
+    pub pool_id: String,
+    pub token_in: Bytes,
+    pub token_out: Bytes,
+    pub token_in_index: u8,
+    pub token_out_index: u8,
+    pub receiver: Bytes,
+    pub transfer_type: u8,
+    pub calldata_hex: String,
+    /// Unix timestamp (seconds) at which the swap was encoded.
+    pub timestamp: u64,
+}
+
+/// Sink for [`SwapAuditEntry`] records produced while encoding swaps.
+///
+/// Implementations must be cheap and infallible from the caller's perspective: `record` returns
+/// nothing and swallows backend errors, so the encode path is never failed by auditing. `record`
+/// is called synchronously from `encode_swap`, so the time a store spends writing is spent there.
+pub trait SwapAuditStore: Send + Sync {
+    /// Persists a single encoded-swap record on a best-effort basis.
+    fn record(&self, entry: &SwapAuditEntry);
+
+    /// Whether this store actually persists records. Lets the encoder skip building the record
+    /// (and hex-encoding the calldata) entirely when auditing is disabled. Defaults to `true`.
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+/// The default store, which discards every record. Used whenever auditing is not configured.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopAuditStore;
+
+impl SwapAuditStore for NoopAuditStore {
+    fn record(&self, _entry: &SwapAuditEntry) {}
+
+    fn enabled(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "sqlite_audit")]
+pub use sqlite::SqliteAuditStore;
+
+#[cfg(feature = "sqlite_audit")]
+mod sqlite {
+    use std::sync::Mutex;
+
+    use rusqlite::{params, Connection};
+
+    use super::{SwapAuditEntry, SwapAuditStore};
+    use crate::encoding::errors::EncodingError;
+
+    /// SQLite-backed [`SwapAuditStore`]. Opens the database in WAL mode so external tooling can read
+    /// the audit history concurrently while an encoding process keeps writing to it.
+    pub struct SqliteAuditStore {
+        conn: Mutex<Connection>,
+    }
+
+    impl SqliteAuditStore {
+        /// Opens (creating if necessary) the audit database at `path` and ensures the schema exists.
+        pub fn open(path: &str) -> Result<Self, EncodingError> {
+            let conn = Connection::open(path).map_err(|err| {
+                EncodingError::FatalError(format!("LiqP audit store: cannot open '{path}': {err}"))
+            })?;
+            // WAL lets readers run concurrently with the single writer. `PRAGMA journal_mode`
+            // returns the resulting mode as a row, so use execute_batch which ignores it.
+            conn.execute_batch("PRAGMA journal_mode=WAL;").map_err(|err| {
+                EncodingError::FatalError(format!("LiqP audit store: cannot enable WAL: {err}"))
+            })?;
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS encoded_swaps (
+                    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+                    chain           TEXT NOT NULL,
+                    pool_id         TEXT NOT NULL,
+                    token_in        TEXT NOT NULL,
+                    token_out       TEXT NOT NULL,
+                    token_in_index  INTEGER NOT NULL,
+                    token_out_index INTEGER NOT NULL,
+                    receiver        TEXT NOT NULL,
+                    transfer_type   INTEGER NOT NULL,
+                    calldata_hex    TEXT NOT NULL,
+                    timestamp       INTEGER NOT NULL
+                )",
+                [],
+            )
+            .map_err(|err| {
+                EncodingError::FatalError(format!("LiqP audit store: cannot create schema: {err}"))
+            })?;
+            Ok(Self { conn: Mutex::new(conn) })
+        }
+    }
+
+    impl SwapAuditStore for SqliteAuditStore {
+        fn record(&self, entry: &SwapAuditEntry) {
+            // Best-effort: a poisoned lock or a write error must not fail the encode path.
+            let Ok(conn) = self.conn.lock() else {
+                return;
+            };
+            let _ = conn.execute(
+                "INSERT INTO encoded_swaps (
+                    chain, pool_id, token_in, token_out, token_in_index,
+                    token_out_index, receiver, transfer_type, calldata_hex, timestamp
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    entry.chain.to_string(),
+                    entry.pool_id,
+                    entry.token_in.to_string(),
+                    entry.token_out.to_string(),
+                    entry.token_in_index,
+                    entry.token_out_index,
+                    entry.receiver.to_string(),
+                    entry.transfer_type,
+                    entry.calldata_hex,
+                    entry.timestamp,
+                ],
+            );
+        }
+    }
+}
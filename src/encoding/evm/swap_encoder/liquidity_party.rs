@@ -1,24 +1,285 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::{SystemTime, UNIX_EPOCH}};
+use alloy::primitives::{keccak256, Address as AlloyAddress, B256, U256};
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::rpc::types::{AccessList, AccessListItem};
+use alloy::sol;
 use alloy::sol_types::SolValue;
 use tycho_common::{models::Chain, Bytes};
 use tycho_common::models::Address;
+use tycho_common::models::protocol::ProtocolComponent;
 use crate::encoding::{
     errors::EncodingError,
     evm::utils::bytes_to_address,
-    models::{EncodingContext, Swap},
+    models::{EncodingContext, Swap, TransferType},
     swap_encoder::SwapEncoder,
 };
+use super::audit::{NoopAuditStore, SwapAuditEntry, SwapAuditStore};
+
+sol! {
+    /// Typed payload passed to the on-chain Liquidity Party executor. Encoding this struct in
+    /// [`AbiEncoding::Standard`] mode lets executors recover the arguments with `abi.decode`; the
+    /// [`AbiEncoding::Packed`] encoding reproduces the historical tightly-packed byte layout.
+    #[derive(Debug, PartialEq, Eq)]
+    struct LiquidityPartySwap {
+        address pool;
+        address tokenIn;
+        uint8 tokenInIndex;
+        uint8 tokenOutIndex;
+        address receiver;
+        uint8 transferType;
+    }
+}
+
+/// Selects how [`LiquidityPartySwapEncoder::encode_swap`] lays out the [`LiquidityPartySwap`]
+/// payload. `Packed` (the default) keeps the legacy tightly-packed bytes current executors expect;
+/// `Standard` emits a 32-byte-aligned, `abi.decode`-friendly, self-describing encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AbiEncoding {
+    Packed,
+    Standard,
+}
+
+/// A token as presented to a signer: its address, plus an optional symbol and decimals.
+///
+/// A bare [`ProtocolComponent`] only carries token *addresses*, so the symbol/decimals are `None`
+/// unless an integrator has opted in to the overlay convention described on
+/// [`LiquidityPartySwapEncoder::resolve_token`]. Renderers must always be able to fall back to the
+/// raw address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClearSignToken {
+    pub address: Address,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+}
+
+/// Human-readable description of the swap a set of calldata performs, for clear-signing on hardware
+/// wallets and in custody workflows.
+///
+/// It is built from the same [`Swap`]/[`EncodingContext`] as
+/// [`LiquidityPartySwapEncoder::encode_swap`], so what a signer confirms is guaranteed to match the
+/// bytes being signed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClearSignMetadata {
+    pub pool: Address,
+    pub token_in: ClearSignToken,
+    pub token_out: ClearSignToken,
+    pub receiver: Address,
+    pub transfer_type: TransferType,
+}
 
 /// Encodes a swap on a Liquidity Party pool through the given executor address.
 ///
 /// # Fields
 /// * `executor_address` - The address of the executor contract that will perform the swap.
+/// * `config` - Optional per-encoder configuration. Used, among other things, to supply the
+///   ERC20 storage mapping slots consumed by [`LiquidityPartySwapEncoder::encode_access_list`].
+/// * `encoding` - The ABI layout produced by [`LiquidityPartySwapEncoder::encode_swap`].
+/// * `rpc_url` - Optional JSON-RPC endpoint enabling [`LiquidityPartySwapEncoder::validate`]. When
+///   absent, encoding stays fully offline and no network dependency is introduced.
+/// * `chain` - The chain this encoder targets, recorded alongside each audited swap.
+/// * `audit_store` - Best-effort sink recording every encoded swap; defaults to a no-op.
 #[derive(Clone)]
 pub struct LiquidityPartySwapEncoder {
     executor_address: Bytes,
+    config: HashMap<String, String>,
+    encoding: AbiEncoding,
+    rpc_url: Option<String>,
+    chain: Chain,
+    audit_store: Arc<dyn SwapAuditStore>,
 }
 
 impl LiquidityPartySwapEncoder {
+    /// Parses the pool address from the component id.
+    fn pool_address(swap: &Swap) -> Result<Address, EncodingError> {
+        Address::from_str(&swap.component().id).map_err(|_| {
+            EncodingError::FatalError("LiqP swap encoder: invalid component id".to_string())
+        })
+    }
+
+    /// Computes the storage slot of a Solidity `mapping(_ => _)` entry as
+    /// `keccak256(key_padded32 ++ slot_padded32)`.
+    fn mapping_slot(key: &B256, slot: &B256) -> B256 {
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(key.as_slice());
+        buf[32..].copy_from_slice(slot.as_slice());
+        keccak256(buf)
+    }
+
+    /// Reads the base mapping slot `key` from `config`, defaulting to `default` when absent.
+    fn base_slot(&self, token: &AlloyAddress, suffix: &str, default: u64) -> B256 {
+        let key = format!("{token:#x}_{suffix}");
+        let slot = self
+            .config
+            .get(&key)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(default);
+        B256::from(U256::from(slot))
+    }
+
+    /// Predicts the storage the swap will touch and returns it as an EIP-2930 access list.
+    ///
+    /// For every ERC20 token involved in the swap this computes the `balanceOf` slot of both the
+    /// pool and the receiver, and the allowance slot granted by the pool to the executor. The base
+    /// `balanceOf`/`allowance` mapping slots are taken from `config` (keyed by
+    /// `"{token:#x}_balance_slot"` / `"{token:#x}_allowance_slot"`, defaulting to slot `0`/`1`).
+    /// The pool address and every token in `swap.component().tokens` are always included as touched
+    /// addresses — even with an empty slot list, listing an account still pre-warms it.
+    pub fn encode_access_list(
+        &self,
+        swap: &Swap,
+        encoding_context: &EncodingContext,
+    ) -> Result<AccessList, EncodingError> {
+        let pool_addr = Self::pool_address(swap)?;
+        let pool = bytes_to_address(&pool_addr)?;
+        let receiver = bytes_to_address(&encoding_context.receiver)?;
+        let executor = bytes_to_address(&self.executor_address)?;
+
+        let mut items: Vec<AccessListItem> = Vec::new();
+
+        for token_bytes in [swap.token_in(), swap.token_out()] {
+            let token = bytes_to_address(token_bytes)?;
+            let balance_slot = self.base_slot(&token, "balance_slot", 0);
+            let allowance_slot = self.base_slot(&token, "allowance_slot", 1);
+
+            let pool_key = B256::left_padding_from(pool.as_slice());
+            let receiver_key = B256::left_padding_from(receiver.as_slice());
+            let executor_key = B256::left_padding_from(executor.as_slice());
+
+            let pool_balance = Self::mapping_slot(&pool_key, &balance_slot);
+            let receiver_balance = Self::mapping_slot(&receiver_key, &balance_slot);
+            let owner_inner = Self::mapping_slot(&pool_key, &allowance_slot);
+            let allowance = Self::mapping_slot(&executor_key, &owner_inner);
+
+            items.push(AccessListItem {
+                address: token,
+                storage_keys: vec![pool_balance, receiver_balance, allowance],
+            });
+        }
+
+        // Pre-warm the pool account and every pool token, even without specific slots. Addresses
+        // already listed above (token_in/token_out) keep their richer slot lists instead of being
+        // duplicated with an empty one.
+        let mut seen: Vec<AlloyAddress> = items.iter().map(|i| i.address).collect();
+        for token_bytes in std::iter::once(&pool_addr).chain(swap.component().tokens.iter()) {
+            let address = bytes_to_address(token_bytes)?;
+            if !seen.contains(&address) {
+                seen.push(address);
+                items.push(AccessListItem { address, storage_keys: vec![] });
+            }
+        }
+
+        Ok(AccessList(items))
+    }
+
+    /// Pre-flight checks that the executor, pool and swap tokens are deployed contracts on the
+    /// chain behind the configured `rpc_url` (config key `"rpc_url"`).
+    ///
+    /// Each address is queried with `eth_getCode`; an empty response means the account has no
+    /// bytecode, i.e. the executor/pool does not exist or a token/pool address belongs to the wrong
+    /// chain — in that case this refuses to proceed with a distinct
+    /// [`EncodingError::PreflightValidation`] (so callers can tell a pre-flight failure apart from
+    /// other encoding errors) rather than handing back calldata that is guaranteed to revert. When
+    /// no `rpc_url` is configured this is a
+    /// no-op returning `Ok(())`, so offline encoding remains the default.
+    ///
+    /// The synchronous [`SwapEncoder::encode_swap`] stays offline and does not call this; use
+    /// [`Self::validate_and_encode_swap`] to run the pre-flight and encode in one step.
+    pub async fn validate(
+        &self,
+        swap: &Swap,
+        _encoding_context: &EncodingContext,
+    ) -> Result<(), EncodingError> {
+        let Some(rpc_url) = self.rpc_url.as_deref() else {
+            return Ok(());
+        };
+        let url = rpc_url.parse().map_err(|_| {
+            EncodingError::FatalError(format!("LiqP swap encoder: invalid rpc_url '{rpc_url}'"))
+        })?;
+        let provider = ProviderBuilder::new().on_http(url);
+
+        let pool_addr = Self::pool_address(swap)?;
+
+        let targets = [
+            ("executor", &self.executor_address),
+            ("pool", &pool_addr),
+            ("token_in", swap.token_in()),
+            ("token_out", swap.token_out()),
+        ];
+
+        for (label, address) in targets {
+            let address = bytes_to_address(address)?;
+            let code = provider.get_code_at(address).await.map_err(|err| {
+                EncodingError::FatalError(format!(
+                    "LiqP swap encoder: eth_getCode failed for {label} {address}: {err}"
+                ))
+            })?;
+            if code.is_empty() {
+                return Err(EncodingError::PreflightValidation(format!(
+                    "LiqP swap encoder: {label} {address} has no bytecode on the configured chain"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs [`Self::validate`] and, only if it succeeds, encodes the swap.
+    ///
+    /// This is the entry point that actually enforces the pre-flight "refuse to encode on empty
+    /// bytecode" behaviour: [`SwapEncoder::encode_swap`] itself is synchronous and offline, so
+    /// callers who want the on-chain existence checks must go through here (or call
+    /// [`Self::validate`] themselves first). With no `rpc_url` configured `validate` is a no-op and
+    /// this is equivalent to calling `encode_swap` directly.
+    pub async fn validate_and_encode_swap(
+        &self,
+        swap: &Swap,
+        encoding_context: &EncodingContext,
+    ) -> Result<Vec<u8>, EncodingError> {
+        self.validate(swap, encoding_context).await?;
+        self.encode_swap(swap, encoding_context)
+    }
+
+    /// Returns a clear-signing description of the swap for signer confirmation.
+    ///
+    /// Token symbols and decimals are resolved via the opt-in overlay convention documented on
+    /// [`Self::resolve_token`] and fall back to the raw address otherwise. Because the description is
+    /// derived from the same `swap`/`encoding_context` handed to [`Self::encode_swap`], it always
+    /// matches the encoded bytes.
+    pub fn describe(
+        &self,
+        swap: &Swap,
+        encoding_context: &EncodingContext,
+    ) -> Result<ClearSignMetadata, EncodingError> {
+        let component = swap.component();
+        Ok(ClearSignMetadata {
+            pool: Self::pool_address(swap)?,
+            token_in: Self::resolve_token(component, swap.token_in()),
+            token_out: Self::resolve_token(component, swap.token_out()),
+            receiver: encoding_context.receiver.clone(),
+            transfer_type: encoding_context.transfer_type,
+        })
+    }
+
+    /// Builds a [`ClearSignToken`] for `token`.
+    ///
+    /// A [`ProtocolComponent`] only lists token addresses, not symbols or decimals, so there is no
+    /// upstream token-metadata field to read. Instead this honours an explicit, opt-in overlay
+    /// convention on the component's [`ProtocolComponent::static_attributes`]: an integrator that
+    /// knows a token's symbol/decimals may stash them under `"{token}_symbol"` (UTF-8 bytes) and
+    /// `"{token}_decimals"` (the value's trailing byte). When the keys are absent — the common case
+    /// — the fields stay `None` and renderers fall back to the raw address.
+    fn resolve_token(component: &ProtocolComponent, token: &Address) -> ClearSignToken {
+        let symbol = component
+            .static_attributes
+            .get(&format!("{token}_symbol"))
+            .and_then(|raw| String::from_utf8(raw.to_vec()).ok());
+        let decimals = component
+            .static_attributes
+            .get(&format!("{token}_decimals"))
+            .and_then(|raw| raw.to_vec().last().copied());
+        ClearSignToken { address: token.clone(), symbol, decimals }
+    }
+
     fn get_token_indexes(&self, swap: &Swap) -> Result<(Bytes, u8, u8), EncodingError> {
         let token_addresses: &Vec<Address> = &swap.component().tokens;
         let token_in = swap.token_in();
@@ -45,10 +306,43 @@ impl LiquidityPartySwapEncoder {
 impl SwapEncoder for LiquidityPartySwapEncoder {
     fn new(
         executor_address: Bytes,
-        _chain: Chain,
-        _config: Option<HashMap<String, String>>,
+        chain: Chain,
+        config: Option<HashMap<String, String>>,
     ) -> Result<Self, EncodingError> {
-        Ok(Self { executor_address })
+        let config = config.unwrap_or_default();
+        let encoding = match config.get("abi_encoding").map(String::as_str) {
+            None | Some("packed") => AbiEncoding::Packed,
+            Some("standard") => AbiEncoding::Standard,
+            Some(other) => {
+                return Err(EncodingError::FatalError(format!(
+                    "LiqP swap encoder: unknown abi_encoding '{other}'"
+                )))
+            }
+        };
+        let rpc_url = config.get("rpc_url").cloned();
+
+        // Auditing is opt-in: with no `audit_db` path recording is a no-op; a configured path
+        // selects the SQLite store, which needs the `sqlite_audit` feature.
+        #[cfg(feature = "sqlite_audit")]
+        let audit_store: Arc<dyn SwapAuditStore> = match config.get("audit_db") {
+            Some(path) => Arc::new(super::audit::SqliteAuditStore::open(path)?),
+            None => Arc::new(NoopAuditStore),
+        };
+        // Without the `sqlite_audit` feature there is no backing store, so a configured `audit_db`
+        // cannot be honoured. Fail loudly rather than silently dropping every audit record.
+        #[cfg(not(feature = "sqlite_audit"))]
+        let audit_store: Arc<dyn SwapAuditStore> = match config.get("audit_db") {
+            Some(_) => {
+                return Err(EncodingError::FatalError(
+                    "LiqP swap encoder: `audit_db` is set but the encoder was built without the \
+                     `sqlite_audit` feature"
+                        .to_string(),
+                ))
+            }
+            None => Arc::new(NoopAuditStore),
+        };
+
+        Ok(Self { executor_address, config, encoding, rpc_url, chain, audit_store })
     }
 
     fn encode_swap(
@@ -56,21 +350,48 @@ impl SwapEncoder for LiquidityPartySwapEncoder {
         swap: &Swap,
         encoding_context: &EncodingContext,
     ) -> Result<Vec<u8>, EncodingError> {
-        let pool_addr = Address::from_str(&swap.component().id)
-            .map_err(|_| EncodingError::FatalError("LiqP swap encoder: invalid component id".to_string()))?;
+        let pool_addr = Self::pool_address(swap)?;
 
         let (token_in, token_in_idx, token_out_idx) = self.get_token_indexes(swap)?;
 
-        let args = (
-            bytes_to_address(&pool_addr)?,
-            bytes_to_address(&token_in)?,
-            token_in_idx.to_be_bytes(),
-            token_out_idx.to_be_bytes(),
-            bytes_to_address(&encoding_context.receiver)?,
-            (encoding_context.transfer_type as u8).to_be_bytes(),
-        );
+        let payload = LiquidityPartySwap {
+            pool: bytes_to_address(&pool_addr)?,
+            tokenIn: bytes_to_address(&token_in)?,
+            tokenInIndex: token_in_idx,
+            tokenOutIndex: token_out_idx,
+            receiver: bytes_to_address(&encoding_context.receiver)?,
+            transferType: encoding_context.transfer_type as u8,
+        };
 
-        Ok(args.abi_encode_packed())
+        let encoded = match self.encoding {
+            AbiEncoding::Packed => payload.abi_encode_packed(),
+            AbiEncoding::Standard => payload.abi_encode(),
+        };
+
+        // Best-effort audit record, written synchronously; a store error never fails the encode
+        // path. Skip building the record
+        // (and hex-encoding the calldata) entirely when the store is disabled, e.g. the default
+        // `NoopAuditStore`.
+        if self.audit_store.enabled() {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            self.audit_store.record(&SwapAuditEntry {
+                chain: self.chain,
+                pool_id: swap.component().id.clone(),
+                token_in,
+                token_out: swap.token_out().clone(),
+                token_in_index: token_in_idx,
+                token_out_index: token_out_idx,
+                receiver: encoding_context.receiver.clone(),
+                transfer_type: encoding_context.transfer_type as u8,
+                calldata_hex: alloy::hex::encode(&encoded),
+                timestamp,
+            });
+        }
+
+        Ok(encoded)
     }
 
     fn executor_address(&self) -> &Bytes {
@@ -141,4 +462,210 @@ mod tests {
         );
         write_calldata_to_file("test_encode_liquidityparty", hex_swap.as_str());
     }
+
+    fn sample_payload() -> LiquidityPartySwap {
+        LiquidityPartySwap {
+            pool: AlloyAddress::from([0x11; 20]),
+            tokenIn: AlloyAddress::from([0x22; 20]),
+            tokenInIndex: 1,
+            tokenOutIndex: 5,
+            receiver: AlloyAddress::from([0x33; 20]),
+            transferType: TransferType::Transfer as u8,
+        }
+    }
+
+    #[test]
+    fn test_standard_encoding_round_trips() {
+        let payload = sample_payload();
+        let encoded = payload.abi_encode();
+        let decoded = LiquidityPartySwap::abi_decode(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_packed_encoding_round_trips() {
+        let payload = sample_payload();
+        let encoded = payload.abi_encode_packed();
+        // Packed layout: pool(20) ++ tokenIn(20) ++ inIdx(1) ++ outIdx(1) ++ receiver(20) ++ type(1)
+        assert_eq!(encoded.len(), 63);
+        let decoded = LiquidityPartySwap {
+            pool: AlloyAddress::from_slice(&encoded[0..20]),
+            tokenIn: AlloyAddress::from_slice(&encoded[20..40]),
+            tokenInIndex: encoded[40],
+            tokenOutIndex: encoded[41],
+            receiver: AlloyAddress::from_slice(&encoded[42..62]),
+            transferType: encoded[62],
+        };
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_describe_falls_back_to_addresses() {
+        let pool = String::from("0xfA0be6148F66A6499666cf790d647D00daB76904");
+        let token_in = Bytes::from("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"); // USDC
+        let token_out = Bytes::from("0xD31a59c85aE9D8edEFeC411D448f90841571b89c"); // WSOL
+        let component = ProtocolComponent {
+            id: pool.clone(),
+            tokens: vec![token_in.clone(), token_out.clone()],
+            ..Default::default()
+        };
+        let swap = Swap::new(component, token_in.clone(), token_out.clone());
+        let encoding_context = EncodingContext {
+            receiver: Bytes::from("0x1D96F2f6BeF1202E4Ce1Ff6Dad0c2CB002861d3e"),
+            exact_out: false,
+            router_address: Some(Bytes::zero(20)),
+            group_token_in: token_in.clone(),
+            group_token_out: token_out.clone(),
+            transfer_type: TransferType::Transfer,
+            historical_trade: false,
+        };
+        let encoder = LiquidityPartySwapEncoder::new(
+            Bytes::from("0x543778987b293C7E8Cf0722BB2e935ba6f4068D4"),
+            Chain::Ethereum,
+            None,
+        )
+        .unwrap();
+
+        let meta = encoder
+            .describe(&swap, &encoding_context)
+            .unwrap();
+
+        assert_eq!(meta.pool, Address::from(pool.as_str()));
+        assert_eq!(meta.token_in.address, token_in);
+        assert_eq!(meta.token_out.address, token_out);
+        // No static attributes were supplied, so symbol/decimals fall back to `None`.
+        assert_eq!(meta.token_in.symbol, None);
+        assert_eq!(meta.token_in.decimals, None);
+        assert_eq!(meta.receiver, encoding_context.receiver);
+    }
+
+    #[test]
+    fn test_describe_resolves_metadata_when_present() {
+        let pool = String::from("0xfA0be6148F66A6499666cf790d647D00daB76904");
+        let token_in = Bytes::from("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"); // USDC
+        let token_out = Bytes::from("0xD31a59c85aE9D8edEFeC411D448f90841571b89c"); // WSOL
+        let mut static_attributes = HashMap::new();
+        static_attributes.insert(format!("{token_in}_symbol"), Bytes::from("USDC".as_bytes().to_vec()));
+        static_attributes.insert(format!("{token_in}_decimals"), Bytes::from(vec![6u8]));
+        let component = ProtocolComponent {
+            id: pool,
+            tokens: vec![token_in.clone(), token_out.clone()],
+            static_attributes,
+            ..Default::default()
+        };
+        let swap = Swap::new(component, token_in.clone(), token_out.clone());
+        let encoding_context = EncodingContext {
+            receiver: Bytes::from("0x1D96F2f6BeF1202E4Ce1Ff6Dad0c2CB002861d3e"),
+            exact_out: false,
+            router_address: Some(Bytes::zero(20)),
+            group_token_in: token_in.clone(),
+            group_token_out: token_out.clone(),
+            transfer_type: TransferType::Transfer,
+            historical_trade: false,
+        };
+        let encoder = LiquidityPartySwapEncoder::new(
+            Bytes::from("0x543778987b293C7E8Cf0722BB2e935ba6f4068D4"),
+            Chain::Ethereum,
+            None,
+        )
+        .unwrap();
+
+        let meta = encoder
+            .describe(&swap, &encoding_context)
+            .unwrap();
+
+        // token_in carries metadata; token_out does not and falls back to the raw address.
+        assert_eq!(meta.token_in.symbol.as_deref(), Some("USDC"));
+        assert_eq!(meta.token_in.decimals, Some(6));
+        assert_eq!(meta.token_out.symbol, None);
+        assert_eq!(meta.token_out.decimals, None);
+    }
+
+    #[test]
+    fn test_encode_access_list_storage_keys() {
+        let pool = String::from("0xfA0be6148F66A6499666cf790d647D00daB76904");
+        let token_in = Bytes::from("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let token_out = Bytes::from("0xD31a59c85aE9D8edEFeC411D448f90841571b89c");
+        let extra = Bytes::from("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"); // WETH, pre-warm only
+        let component = ProtocolComponent {
+            id: pool.clone(),
+            tokens: vec![token_in.clone(), token_out.clone(), extra.clone()],
+            ..Default::default()
+        };
+        let swap = Swap::new(component, token_in.clone(), token_out.clone());
+        let executor = Bytes::from("0x543778987b293C7E8Cf0722BB2e935ba6f4068D4");
+        let encoding_context = EncodingContext {
+            receiver: Bytes::from("0x1D96F2f6BeF1202E4Ce1Ff6Dad0c2CB002861d3e"),
+            exact_out: false,
+            router_address: Some(Bytes::zero(20)),
+            group_token_in: token_in.clone(),
+            group_token_out: token_out.clone(),
+            transfer_type: TransferType::Transfer,
+            historical_trade: false,
+        };
+        let encoder =
+            LiquidityPartySwapEncoder::new(executor.clone(), Chain::Ethereum, None).unwrap();
+
+        let access_list = encoder
+            .encode_access_list(&swap, &encoding_context)
+            .unwrap();
+
+        // Independently recompute the expected slots for `token_in` with the default slots 0/1.
+        let pool_a = bytes_to_address(&Bytes::from(pool.as_str())).unwrap();
+        let receiver_a = bytes_to_address(&encoding_context.receiver).unwrap();
+        let executor_a = bytes_to_address(&executor).unwrap();
+        let slot = |key: &B256, base: &B256| {
+            let mut buf = [0u8; 64];
+            buf[..32].copy_from_slice(key.as_slice());
+            buf[32..].copy_from_slice(base.as_slice());
+            keccak256(buf)
+        };
+        let balance_slot = B256::from(U256::from(0u64));
+        let allowance_slot = B256::from(U256::from(1u64));
+        let pool_key = B256::left_padding_from(pool_a.as_slice());
+        let receiver_key = B256::left_padding_from(receiver_a.as_slice());
+        let executor_key = B256::left_padding_from(executor_a.as_slice());
+        let expected = vec![
+            slot(&pool_key, &balance_slot),
+            slot(&receiver_key, &balance_slot),
+            slot(&executor_key, &slot(&pool_key, &allowance_slot)),
+        ];
+
+        let token_in_a = bytes_to_address(&token_in).unwrap();
+        let token_in_item = access_list
+            .0
+            .iter()
+            .find(|item| item.address == token_in_a)
+            .expect("token_in must be present");
+        assert_eq!(token_in_item.storage_keys, expected);
+
+        // token_out carries the same three-slot shape.
+        let token_out_a = bytes_to_address(&token_out).unwrap();
+        let token_out_item = access_list
+            .0
+            .iter()
+            .find(|item| item.address == token_out_a)
+            .expect("token_out must be present");
+        assert_eq!(token_out_item.storage_keys.len(), 3);
+
+        // The pool and the extra pool token are pre-warmed with empty slot lists.
+        let pool_item = access_list
+            .0
+            .iter()
+            .find(|item| item.address == pool_a)
+            .expect("pool must be pre-warmed");
+        assert!(pool_item.storage_keys.is_empty());
+        let extra_a = bytes_to_address(&extra).unwrap();
+        assert!(access_list
+            .0
+            .iter()
+            .any(|item| item.address == extra_a && item.storage_keys.is_empty()));
+
+        // No address is listed twice.
+        let mut addresses: Vec<_> = access_list.0.iter().map(|item| item.address).collect();
+        let total = addresses.len();
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(addresses.len(), total, "access list must not duplicate addresses");
+    }
 }
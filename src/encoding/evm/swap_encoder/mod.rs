@@ -0,0 +1,5 @@
+//! Protocol-specific [`SwapEncoder`](crate::encoding::swap_encoder::SwapEncoder) implementations
+//! and their shared helpers.
+
+mod audit;
+pub mod liquidity_party;